@@ -1,10 +1,12 @@
 //! Generators may generate bytes or, in general, data, for inputs.
 
-use alloc::vec::Vec;
-use core::{cmp::min, marker::PhantomData};
+use alloc::{boxed::Box, vec::Vec};
+use core::{cmp::min, fmt, marker::PhantomData};
+
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    bolts::rands::Rand,
+    bolts::rands::{Rand, StdRand},
     inputs::{bytes::BytesInput, GeneralizedInput, Input},
     state::HasRand,
     Error,
@@ -69,6 +71,9 @@ where
     S: HasRand,
     G: Generator<Input = BytesInput, State = S>,
 {
+    type Input = GeneralizedInput;
+    type State = S;
+
     fn generate(&mut self, state: &mut S) -> Result<GeneralizedInput, Error> {
         Ok(self.bytes_generator.generate(state)?.into())
     }
@@ -80,12 +85,17 @@ where
 
 #[derive(Clone, Debug)]
 /// Generates random bytes
-pub struct RandBytesGenerator {
+pub struct RandBytesGenerator<S> {
     max_size: usize,
+    phantom: PhantomData<S>,
 }
 
-impl Generator for RandBytesGenerator {
+impl<S> Generator for RandBytesGenerator<S>
+where
+    S: HasRand,
+{
     type Input = BytesInput;
+    type State = S;
 
     fn generate(&mut self, state: &mut Self::State) -> Result<BytesInput, Error> {
         let mut size = state.rand_mut().below(self.max_size as u64);
@@ -105,22 +115,30 @@ impl Generator for RandBytesGenerator {
     }
 }
 
-impl RandBytesGenerator {
+impl<S> RandBytesGenerator<S> {
     /// Returns a new [`RandBytesGenerator`], generating up to `max_size` random bytes.
     #[must_use]
     pub fn new(max_size: usize) -> Self {
-        Self { max_size }
+        Self {
+            max_size,
+            phantom: PhantomData,
+        }
     }
 }
 
 #[derive(Clone, Debug)]
 /// Generates random printable characters
-pub struct RandPrintablesGenerator {
+pub struct RandPrintablesGenerator<S> {
     max_size: usize,
+    phantom: PhantomData<S>,
 }
 
-impl Generator for RandPrintablesGenerator {
+impl<S> Generator for RandPrintablesGenerator<S>
+where
+    S: HasRand,
+{
     type Input = BytesInput;
+    type State = S;
 
     fn generate(&mut self, state: &mut Self::State) -> Result<BytesInput, Error> {
         let mut size = state.rand_mut().below(self.max_size as u64);
@@ -141,11 +159,450 @@ impl Generator for RandPrintablesGenerator {
     }
 }
 
-impl RandPrintablesGenerator {
+impl<S> RandPrintablesGenerator<S> {
     /// Creates a new [`RandPrintablesGenerator`], generating up to `max_size` random printable characters.
     #[must_use]
     pub fn new(max_size: usize) -> Self {
-        Self { max_size }
+        Self {
+            max_size,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// The number of distinct byte values, used to size alias tables for [`WeightedBytesGenerator`].
+const ALIAS_TABLE_LEN: usize = 256;
+
+/// Computes a 256-entry byte histogram over a set of inputs, e.g. the current corpus, to seed a
+/// [`WeightedBytesGenerator`].
+#[must_use]
+pub fn byte_histogram<'a>(inputs: impl IntoIterator<Item = &'a [u8]>) -> [u64; 256] {
+    let mut histogram = [0u64; ALIAS_TABLE_LEN];
+    for input in inputs {
+        for &byte in input {
+            histogram[byte as usize] += 1;
+        }
+    }
+    histogram
+}
+
+/// Builds the probability and alias tables for Vose's alias method from a set of (possibly
+/// unnormalized) weights, so that sampling an index is `O(1)`.
+fn build_alias_tables(weights: &[u64; ALIAS_TABLE_LEN]) -> ([f64; ALIAS_TABLE_LEN], [usize; ALIAS_TABLE_LEN]) {
+    let sum: u64 = weights.iter().sum();
+
+    // with no observations yet, fall back to a flat distribution
+    let mut scaled = [0.0_f64; ALIAS_TABLE_LEN];
+    if sum == 0 {
+        for s in &mut scaled {
+            *s = 1.0;
+        }
+    } else {
+        for (s, &w) in scaled.iter_mut().zip(weights.iter()) {
+            *s = (w as f64) * (ALIAS_TABLE_LEN as f64) / (sum as f64);
+        }
+    }
+
+    let mut small: Vec<usize> = Vec::new();
+    let mut large: Vec<usize> = Vec::new();
+    for (i, &s) in scaled.iter().enumerate() {
+        if s < 1.0 {
+            small.push(i);
+        } else {
+            large.push(i);
+        }
+    }
+
+    let mut prob = [0.0_f64; ALIAS_TABLE_LEN];
+    let mut alias = [0_usize; ALIAS_TABLE_LEN];
+
+    while !small.is_empty() && !large.is_empty() {
+        let s = small.pop().unwrap();
+        let l = large.pop().unwrap();
+        prob[s] = scaled[s];
+        alias[s] = l;
+        scaled[l] = scaled[l] + scaled[s] - 1.0;
+        if scaled[l] < 1.0 {
+            small.push(l);
+        } else {
+            large.push(l);
+        }
+    }
+
+    // leftover indices have a scaled weight of 1 (up to floating point rounding), treat them as certain
+    for i in large.into_iter().chain(small) {
+        prob[i] = 1.0;
+    }
+
+    (prob, alias)
+}
+
+#[derive(Clone, Debug)]
+/// Generates bytes sampled from a non-uniform distribution, e.g. a byte histogram computed over
+/// the current corpus, via `O(1)` per-byte sampling with Vose's alias method.
+pub struct WeightedBytesGenerator<S> {
+    max_size: usize,
+    prob: [f64; ALIAS_TABLE_LEN],
+    alias: [usize; ALIAS_TABLE_LEN],
+    phantom: PhantomData<S>,
+}
+
+impl<S> Generator for WeightedBytesGenerator<S>
+where
+    S: HasRand,
+{
+    type Input = BytesInput;
+    type State = S;
+
+    fn generate(&mut self, state: &mut Self::State) -> Result<BytesInput, Error> {
+        let mut size = state.rand_mut().below(self.max_size as u64);
+        if size == 0 {
+            size = 1;
+        }
+        let random_bytes: Vec<u8> = (0..size).map(|_| self.sample_byte(state)).collect();
+        Ok(BytesInput::new(random_bytes))
+    }
+
+    /// Generates up to `DUMMY_BYTES_MAX` non-random dummy bytes (0)
+    fn generate_dummy(&self, _state: &mut Self::State) -> BytesInput {
+        let size = min(self.max_size, DUMMY_BYTES_MAX);
+        BytesInput::new(vec![0; size])
+    }
+}
+
+impl<S> WeightedBytesGenerator<S> {
+    /// Returns a new [`WeightedBytesGenerator`], generating up to `max_size` bytes drawn from
+    /// `histogram`, a per-byte-value frequency table such as the one returned by
+    /// [`byte_histogram`].
+    #[must_use]
+    pub fn new(max_size: usize, histogram: &[u64; ALIAS_TABLE_LEN]) -> Self {
+        let (prob, alias) = build_alias_tables(histogram);
+        Self {
+            max_size,
+            prob,
+            alias,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Rebuilds the alias tables from an updated byte histogram, e.g. after the corpus grew.
+    pub fn update_histogram(&mut self, histogram: &[u64; ALIAS_TABLE_LEN]) {
+        let (prob, alias) = build_alias_tables(histogram);
+        self.prob = prob;
+        self.alias = alias;
+    }
+
+    /// Draws a single byte in `O(1)` using the alias tables.
+    fn sample_byte(&self, state: &mut S) -> u8
+    where
+        S: HasRand,
+    {
+        const FRAC_SCALE: u64 = 1 << 24;
+        let i = state.rand_mut().below(ALIAS_TABLE_LEN as u64) as usize;
+        let frac = state.rand_mut().below(FRAC_SCALE) as f64 / FRAC_SCALE as f64;
+        if frac < self.prob[i] {
+            i as u8
+        } else {
+            self.alias[i] as u8
+        }
+    }
+}
+
+/// A [`Generator`] that blends several sub-generators, selecting one on each call proportional
+/// to its weight and delegating to it.
+pub struct WeightedGenerator<I, S> {
+    generators: Vec<(u64, Box<dyn Generator<Input = I, State = S>>)>,
+}
+
+impl<I, S> fmt::Debug for WeightedGenerator<I, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeightedGenerator")
+            .field(
+                "weights",
+                &self.generators.iter().map(|(w, _)| *w).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<I, S> Default for WeightedGenerator<I, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, S> WeightedGenerator<I, S> {
+    /// Creates a new, empty [`WeightedGenerator`]. Use [`WeightedGenerator::with`] or
+    /// [`WeightedGenerator::push`] to add sub-generators before generating any input.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            generators: Vec::new(),
+        }
+    }
+
+    /// Adds a sub-generator with the given relative `weight`, returning `self` for chaining.
+    #[must_use]
+    pub fn with<G>(mut self, weight: u64, generator: G) -> Self
+    where
+        G: Generator<Input = I, State = S> + 'static,
+    {
+        self.push(weight, generator);
+        self
+    }
+
+    /// Pushes a sub-generator with the given relative `weight`.
+    pub fn push<G>(&mut self, weight: u64, generator: G)
+    where
+        G: Generator<Input = I, State = S> + 'static,
+    {
+        self.generators.push((weight, Box::new(generator)));
+    }
+
+    /// Picks the index of a sub-generator proportional to its weight, via a running-sum draw.
+    /// Returns an [`Error::IllegalState`] if no sub-generator was ever pushed, or if the weights of
+    /// all pushed sub-generators are zero.
+    fn pick<R>(&self, rand: &mut R) -> Result<usize, Error>
+    where
+        R: Rand,
+    {
+        let total: u64 = self.generators.iter().map(|(weight, _)| *weight).sum();
+        if total == 0 {
+            return Err(Error::illegal_state(
+                "WeightedGenerator::generate called with no sub-generators of non-zero weight",
+            ));
+        }
+        let mut choice = rand.below(total);
+        for (i, (weight, _)) in self.generators.iter().enumerate() {
+            if choice < *weight {
+                return Ok(i);
+            }
+            choice -= *weight;
+        }
+        Ok(self.generators.len() - 1)
+    }
+
+    /// Returns the index of the highest-weight sub-generator.
+    fn highest_weight_index(&self) -> usize {
+        self.generators
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, (weight, _))| *weight)
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+}
+
+impl<I, S> Generator for WeightedGenerator<I, S>
+where
+    I: Input,
+    S: HasRand,
+{
+    type Input = I;
+    type State = S;
+
+    fn generate(&mut self, state: &mut Self::State) -> Result<I, Error> {
+        let idx = self.pick(state.rand_mut())?;
+        self.generators[idx].1.generate(state)
+    }
+
+    /// Delegates to the highest-weight sub-generator.
+    ///
+    /// # Panics
+    /// Panics if no sub-generator was ever pushed; unlike [`Self::generate`] this method has no
+    /// `Result` to report that precondition violation through.
+    fn generate_dummy(&self, state: &mut Self::State) -> I {
+        assert!(
+            !self.generators.is_empty(),
+            "WeightedGenerator::generate_dummy called with no sub-generators pushed"
+        );
+        let idx = self.highest_weight_index();
+        self.generators[idx].1.generate_dummy(state)
+    }
+}
+
+/// A [`Generator`] that consumes a shrinking size budget, so composite generators for
+/// records, nested lists, or grammar-like structures can terminate instead of recursing forever.
+pub trait SizedGenerator {
+    type Input: Input;
+    type State;
+
+    /// Generates a new input, recursing with at most `size` levels of budget remaining.
+    fn generate_sized(
+        &mut self,
+        state: &mut Self::State,
+        size: usize,
+    ) -> Result<Self::Input, Error>;
+}
+
+/// Any [`Generator`] satisfies [`SizedGenerator`] by ignoring the budget.
+impl<G> SizedGenerator for G
+where
+    G: Generator,
+{
+    type Input = G::Input;
+    type State = G::State;
+
+    fn generate_sized(
+        &mut self,
+        state: &mut Self::State,
+        _size: usize,
+    ) -> Result<Self::Input, Error> {
+        self.generate(state)
+    }
+}
+
+/// Generates a `Vec` of up to `size` elements with `generator`, halving the size budget passed
+/// to each element so that nested [`SizedGenerator`]s terminate.
+pub fn generate_sized_vec<G>(
+    generator: &mut G,
+    state: &mut G::State,
+    size: usize,
+) -> Result<Vec<G::Input>, Error>
+where
+    G: SizedGenerator,
+    G::State: HasRand,
+{
+    if size == 0 {
+        return Ok(Vec::new());
+    }
+    let len = state.rand_mut().below((size as u64).saturating_add(1)) as usize;
+    (0..len)
+        .map(|_| generator.generate_sized(state, size / 2))
+        .collect()
+}
+
+/// Chooses one of `variants` uniformly at random and drives it with a `size - 1` budget, the
+/// combinator form of "choose one of N variants, passing `size - 1` to the chosen branch."
+///
+/// Returns an [`Error::IllegalArgument`] if `variants` is empty, since there is then nothing to
+/// choose between.
+pub fn generate_sized_choice<S, I>(
+    state: &mut S,
+    size: usize,
+    variants: &mut [&mut dyn SizedGenerator<Input = I, State = S>],
+) -> Result<I, Error>
+where
+    S: HasRand,
+    I: Input,
+{
+    if variants.is_empty() {
+        return Err(Error::illegal_argument(
+            "generate_sized_choice called with no variants to choose between",
+        ));
+    }
+    let idx = state.rand_mut().below(variants.len() as u64) as usize;
+    variants[idx].generate_sized(state, size.saturating_sub(1))
+}
+
+/// An input paired with the RNG seed that produced it. Serializable so a crashing generated
+/// input can be regenerated bit-for-bit on another machine, which the plain [`Generator`] trait
+/// offers no path to.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SeededInput<I> {
+    /// The seed the underlying [`Rand`] was set to right before generation.
+    pub seed: u64,
+    /// The input produced from that seed.
+    pub input: I,
+}
+
+/// Wraps a [`Generator`] and draws the seed for each call from its own private,
+/// independently-advanced [`StdRand`], recording it alongside the produced input so generation
+/// can later be re-driven from that seed to reproduce the exact input. The shared campaign
+/// `state`'s [`Rand`] is only ever swapped out for the duration of a single `generate` call and
+/// restored immediately after, so capturing or replaying a seed never perturbs randomness drawn
+/// by anything else in the fuzzing state.
+#[derive(Clone, Debug)]
+pub struct SeedCapturingGenerator<G> {
+    inner: G,
+    replay_rand: StdRand,
+    reseed_every: Option<u64>,
+    generated_since_reseed: u64,
+}
+
+impl<G> SeedCapturingGenerator<G> {
+    /// Wraps `inner`, capturing the seed used for each call to [`Self::generate_seeded`]. The
+    /// private replay [`Rand`] is seeded from `seed`; pass a value that differs across
+    /// co-running fuzzer instances (e.g. one derived from the current time) to avoid correlated
+    /// seed streams between them.
+    #[must_use]
+    pub fn new(inner: G, seed: u64) -> Self {
+        Self {
+            inner,
+            replay_rand: StdRand::with_seed(seed),
+            reseed_every: None,
+            generated_since_reseed: 0,
+        }
+    }
+
+    /// Reseeds the private replay [`Rand`] after every `count` generated inputs. Never touches
+    /// the shared campaign `state`'s [`Rand`].
+    #[must_use]
+    pub fn reseed_every(mut self, count: u64) -> Self {
+        self.reseed_every = Some(count);
+        self
+    }
+
+    /// Generates a new input, returning it together with the seed that produced it.
+    pub fn generate_seeded<S>(&mut self, state: &mut S) -> Result<SeededInput<G::Input>, Error>
+    where
+        S: HasRand<Rand = StdRand>,
+        G: Generator<State = S>,
+    {
+        if let Some(reseed_every) = self.reseed_every {
+            if self.generated_since_reseed >= reseed_every {
+                let fresh_seed = self.replay_rand.next();
+                self.replay_rand.set_seed(fresh_seed);
+                self.generated_since_reseed = 0;
+            }
+        }
+
+        let seed = self.replay_rand.next();
+        let input = self.generate_with_seed(state, seed)?;
+        self.generated_since_reseed += 1;
+        Ok(SeededInput { seed, input })
+    }
+
+    /// Re-drives generation from a previously captured `seed`, reproducing the exact input
+    /// that was generated from it, without disturbing the shared campaign `state`'s [`Rand`].
+    pub fn regenerate_from_seed<S>(&mut self, state: &mut S, seed: u64) -> Result<G::Input, Error>
+    where
+        S: HasRand<Rand = StdRand>,
+        G: Generator<State = S>,
+    {
+        self.generate_with_seed(state, seed)
+    }
+
+    /// Swaps a freshly-seeded [`StdRand`] into `state` for the duration of one `inner.generate`
+    /// call, then swaps the original back, so the campaign-wide [`Rand`] trajectory is
+    /// untouched by the time this returns.
+    fn generate_with_seed<S>(&mut self, state: &mut S, seed: u64) -> Result<G::Input, Error>
+    where
+        S: HasRand<Rand = StdRand>,
+        G: Generator<State = S>,
+    {
+        let mut local_rand = StdRand::with_seed(seed);
+        core::mem::swap(state.rand_mut(), &mut local_rand);
+        let result = self.inner.generate(state);
+        core::mem::swap(state.rand_mut(), &mut local_rand);
+        result
+    }
+}
+
+impl<G> Generator for SeedCapturingGenerator<G>
+where
+    G: Generator,
+    G::State: HasRand<Rand = StdRand>,
+{
+    type Input = G::Input;
+    type State = G::State;
+
+    fn generate(&mut self, state: &mut Self::State) -> Result<G::Input, Error> {
+        Ok(self.generate_seeded(state)?.input)
+    }
+
+    fn generate_dummy(&self, state: &mut Self::State) -> G::Input {
+        self.inner.generate_dummy(state)
     }
 }
 
@@ -158,7 +615,7 @@ pub mod pybind {
     use pyo3::prelude::*;
 
     use crate::{
-        generators::{Generator, RandBytesGenerator, RandPrintablesGenerator},
+        generators::{Generator, RandBytesGenerator, RandPrintablesGenerator, WeightedGenerator},
         inputs::{BytesInput, HasBytesVec},
         state::pybind::{PythonStdState, PythonStdStateWrapper},
         Error,
@@ -203,7 +660,7 @@ pub mod pybind {
     /// Python class for RandBytesGenerator
     pub struct PythonRandBytesGenerator {
         /// Rust wrapped RandBytesGenerator object
-        pub inner: RandBytesGenerator<State = PythonStdState>,
+        pub inner: RandBytesGenerator<PythonStdState>,
     }
 
     #[pymethods]
@@ -258,10 +715,47 @@ pub mod pybind {
         }
     }
 
+    #[pyclass(unsendable, name = "WeightedGenerator")]
+    #[derive(Debug)]
+    /// Python class for WeightedGenerator
+    pub struct PythonWeightedGenerator {
+        /// Rust wrapped WeightedGenerator object
+        pub inner: WeightedGenerator<BytesInput, PythonStdState>,
+    }
+
+    #[pymethods]
+    impl PythonWeightedGenerator {
+        #[new]
+        fn new() -> Self {
+            Self {
+                inner: WeightedGenerator::new(),
+            }
+        }
+
+        /// Adds a sub-generator with the given relative `weight`
+        fn push(&mut self, weight: u64, py_gen: Py<PythonGenerator>) {
+            let generator = Python::with_gil(|py| py_gen.borrow(py).clone());
+            self.inner.push(weight, generator);
+        }
+
+        fn generate(&mut self, state: &mut PythonStdStateWrapper) -> Vec<u8> {
+            self.inner
+                .generate(state.unwrap_mut())
+                .expect("PythonWeightedGenerator::generate failed")
+                .bytes()
+                .to_vec()
+        }
+
+        fn as_generator(slf: Py<Self>) -> PythonGenerator {
+            PythonGenerator::new_weighted(slf)
+        }
+    }
+
     #[derive(Debug, Clone)]
     enum PythonGeneratorWrapper {
         RandBytes(Py<PythonRandBytesGenerator>),
         RandPrintables(Py<PythonRandPrintablesGenerator>),
+        Weighted(Py<PythonWeightedGenerator>),
         Python(PyObjectGenerator),
     }
 
@@ -275,7 +769,7 @@ pub mod pybind {
     macro_rules! unwrap_me {
         ($wrapper:expr, $name:ident, $body:block) => {
             crate::unwrap_me_body!($wrapper, $name, $body, PythonGeneratorWrapper,
-                { RandBytes, RandPrintables },
+                { RandBytes, RandPrintables, Weighted },
                 {
                     Python(py_wrapper) => {
                         let $name = py_wrapper;
@@ -289,7 +783,7 @@ pub mod pybind {
     macro_rules! unwrap_me_mut {
         ($wrapper:expr, $name:ident, $body:block) => {
             crate::unwrap_me_mut_body!($wrapper, $name, $body, PythonGeneratorWrapper,
-                { RandBytes, RandPrintables },
+                { RandBytes, RandPrintables, Weighted },
                 {
                     Python(py_wrapper) => {
                         let $name = py_wrapper;
@@ -316,6 +810,13 @@ pub mod pybind {
             }
         }
 
+        #[staticmethod]
+        fn new_weighted(py_gen: Py<PythonWeightedGenerator>) -> Self {
+            Self {
+                wrapper: PythonGeneratorWrapper::Weighted(py_gen),
+            }
+        }
+
         #[staticmethod]
         #[must_use]
         pub fn new_py(obj: PyObject) -> Self {
@@ -347,7 +848,114 @@ pub mod pybind {
     pub fn register(_py: Python, m: &PyModule) -> PyResult<()> {
         m.add_class::<PythonRandBytesGenerator>()?;
         m.add_class::<PythonRandPrintablesGenerator>()?;
+        m.add_class::<PythonWeightedGenerator>()?;
         m.add_class::<PythonGenerator>()?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{inputs::HasBytesVec, state::NopState};
+
+    #[test]
+    fn weighted_bytes_generator_tracks_skewed_histogram() {
+        let mut state: NopState<BytesInput> = NopState::new();
+
+        let mut histogram = [1u64; ALIAS_TABLE_LEN];
+        histogram[0] = 10_000;
+        let generator: WeightedBytesGenerator<NopState<BytesInput>> =
+            WeightedBytesGenerator::new(4096, &histogram);
+
+        let samples = 20_000u64;
+        let mut zero_count = 0u64;
+        for _ in 0..samples {
+            if generator.sample_byte(&mut state) == 0 {
+                zero_count += 1;
+            }
+        }
+
+        // byte 0 was weighted ~10000x any other single byte, it should dominate the draws
+        assert!(zero_count * 2 > samples);
+    }
+
+    #[test]
+    fn seed_capturing_generator_reproduces_output_from_seed() {
+        let mut state: NopState<BytesInput> = NopState::new();
+        let inner: RandBytesGenerator<NopState<BytesInput>> = RandBytesGenerator::new(32);
+        let mut generator = SeedCapturingGenerator::new(inner, 1337);
+
+        let seeded = generator.generate_seeded(&mut state).unwrap();
+        let replayed = generator
+            .regenerate_from_seed(&mut state, seeded.seed)
+            .unwrap();
+
+        assert_eq!(seeded.input.bytes(), replayed.bytes());
+    }
+
+    /// A [`Generator`] that always produces a single fixed byte, used to tell which
+    /// sub-generator [`WeightedGenerator`] dispatched to.
+    #[derive(Clone, Debug)]
+    struct ConstByteGenerator(u8);
+
+    impl Generator for ConstByteGenerator {
+        type Input = BytesInput;
+        type State = NopState<BytesInput>;
+
+        fn generate(&mut self, _state: &mut Self::State) -> Result<BytesInput, Error> {
+            Ok(BytesInput::new(vec![self.0]))
+        }
+
+        fn generate_dummy(&self, _state: &mut Self::State) -> BytesInput {
+            BytesInput::new(vec![self.0])
+        }
+    }
+
+    #[test]
+    fn weighted_generator_errs_with_no_sub_generators() {
+        let mut state: NopState<BytesInput> = NopState::new();
+        let mut generator: WeightedGenerator<BytesInput, NopState<BytesInput>> =
+            WeightedGenerator::new();
+
+        assert!(generator.generate(&mut state).is_err());
+    }
+
+    #[test]
+    fn weighted_generator_dispatch_tracks_weights() {
+        let mut state: NopState<BytesInput> = NopState::new();
+        let mut generator = WeightedGenerator::new()
+            .with(9, ConstByteGenerator(1))
+            .with(1, ConstByteGenerator(2));
+
+        let samples = 2_000u64;
+        let mut heavy_count = 0u64;
+        for _ in 0..samples {
+            if generator.generate(&mut state).unwrap().bytes() == [1] {
+                heavy_count += 1;
+            }
+        }
+
+        // weighted 9:1 in favor of byte 1, it should dominate the draws
+        assert!(heavy_count * 2 > samples);
+    }
+
+    #[test]
+    fn generate_sized_vec_terminates_at_zero_budget() {
+        let mut state: NopState<BytesInput> = NopState::new();
+        let mut generator: RandBytesGenerator<NopState<BytesInput>> = RandBytesGenerator::new(32);
+
+        let result = generate_sized_vec(&mut generator, &mut state, 0).unwrap();
+
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn generate_sized_choice_errs_with_no_variants() {
+        let mut state: NopState<BytesInput> = NopState::new();
+        let mut variants: [&mut dyn SizedGenerator<Input = BytesInput, State = NopState<BytesInput>>;
+            0] = [];
+
+        assert!(generate_sized_choice(&mut state, 4, &mut variants).is_err());
+    }
+}